@@ -0,0 +1,197 @@
+//! Low-level lexing of CoNLL-U source text.
+//!
+//! Splitting source text into lines/fields used to be done with manual
+//! `str::split`/`strip_prefix` calls. This module instead drives
+//! [logos::Logos]-generated tokenizers, so newlines, `#`-comment lines, tab
+//! separators, the `key=value|key=value` grammar used by the `FEATS`/`MISC`
+//! columns, and the `ID` column's `3`/`3-4`/`3.1` shapes are all recognized
+//! as distinct token kinds, each carrying its byte span into the input for
+//! free. Blank lines have no token kind of their own, and nothing in this
+//! module splits text into sentences: [crate::parsers::Doc] carves a file
+//! into blank-line-delimited blocks by reading it line by line, and each
+//! block is then lexed here as one sentence. [crate::parsers] and
+//! [crate::token] are the only consumers of this module; nothing here is
+//! part of the public API.
+//!
+//! This module depends on the `logos` crate. This tree has no `Cargo.toml`
+//! of its own (none exists in this repo snapshot, before or after this
+//! change), so the `logos` dependency bump is not part of this patch and
+//! must land in the manifest separately before this module will build.
+
+use logos::Logos;
+
+use crate::token::TokenID;
+
+#[derive(Logos, Debug, Clone, Copy, PartialEq, Eq)]
+enum SourceTok<'s> {
+    #[token("\n")]
+    Newline,
+
+    #[regex(r"#[^\n]*", |lex| lex.slice(), priority = 3)]
+    Comment(&'s str),
+
+    #[regex(r"[^\n]+", |lex| lex.slice())]
+    TokenLine(&'s str),
+}
+
+/// One non-blank line of CoNLL-U source, classified by the role it plays
+/// in sentence structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Line<'s> {
+    /// A `# ...` metadata/comment line.
+    Comment(&'s str),
+    /// An ordinary tab-separated token line.
+    Token(&'s str),
+}
+
+/// Walk `source` line by line, classifying each non-blank line as a
+/// comment or a token line and pairing it with the 1-based line number and
+/// absolute byte offset it starts at. Blank lines produce no entry — they
+/// fall out of the gap between two `Newline` tokens with no
+/// `Comment`/`TokenLine` token in between — and show up only as a jump in
+/// line numbers between consecutive entries; the callers in
+/// [crate::parsers] do not split sentences on them.
+pub(crate) fn lex_lines(source: &str) -> Vec<(usize, usize, Line<'_>)> {
+    let mut out = Vec::with_capacity(source.len() / 16);
+    let mut lexer = SourceTok::lexer(source);
+    let mut line_no = 1usize;
+    let mut line_start = 0usize;
+
+    for token in &mut lexer {
+        let span = lexer.span();
+        match token {
+            Ok(SourceTok::TokenLine(text)) => {
+                out.push((line_no, line_start, Line::Token(strip_cr(text))));
+            }
+            Ok(SourceTok::Comment(text)) => {
+                out.push((line_no, line_start, Line::Comment(strip_cr(text))));
+            }
+            Ok(SourceTok::Newline) => {
+                line_no += 1;
+                line_start = span.end;
+            }
+            Err(_) => {}
+        }
+    }
+
+    out
+}
+
+fn strip_cr(text: &str) -> &str {
+    text.strip_suffix('\r').unwrap_or(text)
+}
+
+#[derive(Logos, Debug, Clone, Copy, PartialEq, Eq)]
+enum LineTok<'s> {
+    #[token("\t")]
+    Tab,
+
+    #[regex(r"[^\t]+", |lex| lex.slice())]
+    Field(&'s str),
+}
+
+/// Split one CoNLL-U line into its tab-separated fields, each paired with
+/// its byte column within the line. Mirrors `line.split('\t')`, including
+/// the empty fields produced by consecutive or trailing tabs.
+pub(crate) fn lex_fields(line: &str) -> Vec<(&str, usize)> {
+    let mut fields = Vec::with_capacity(10);
+    let mut lexer = LineTok::lexer(line);
+    let mut column = 0usize;
+    let mut pending_field = false;
+
+    for token in &mut lexer {
+        let span = lexer.span();
+        match token {
+            Ok(LineTok::Field(text)) => {
+                fields.push((text, span.start));
+                pending_field = true;
+            }
+            Ok(LineTok::Tab) => {
+                if !pending_field {
+                    fields.push(("", span.start));
+                }
+                pending_field = false;
+            }
+            Err(_) => {}
+        }
+        column = span.end;
+    }
+    if !pending_field {
+        fields.push(("", column));
+    }
+
+    fields
+}
+
+#[derive(Logos, Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyValueTok<'s> {
+    #[token("|")]
+    Pipe,
+
+    #[regex(r"[^|]+", |lex| lex.slice())]
+    Entry(&'s str),
+}
+
+/// Parse the `key=value|key=value` grammar used by the `FEATS` and `MISC`
+/// columns, in source order. Each `|`-separated entry is split on its
+/// *first* `=`, so a value containing further `=` characters (or none at
+/// all, i.e. an empty value) is preserved verbatim — matching the
+/// `str::split_once('=')` semantics this lexer replaces.
+pub(crate) fn lex_key_value_list(text: &str) -> Vec<(&str, &str)> {
+    let mut pairs = Vec::new();
+    let mut lexer = KeyValueTok::lexer(text);
+
+    for token in &mut lexer {
+        if let Ok(KeyValueTok::Entry(entry)) = token {
+            if let Some(pair) = entry.split_once('=') {
+                pairs.push(pair);
+            }
+        }
+    }
+
+    pairs
+}
+
+#[derive(Logos, Debug, Clone, Copy, PartialEq, Eq)]
+enum IdTok {
+    #[regex(r"[0-9]+", |lex| lex.slice().parse().ok())]
+    Number(usize),
+
+    #[token("-")]
+    Dash,
+
+    #[token(".")]
+    Dot,
+}
+
+/// Parse one `ID` column value (`"3"`, `"3-4"`, or `"3.1"`) by peeking the
+/// separator that follows the first number, rather than searching the
+/// whole string for `-`/`.` up front. Never reallocates: each number is
+/// parsed straight out of its token slice.
+pub(crate) fn lex_token_id(text: &str) -> Option<TokenID> {
+    let mut lexer = IdTok::lexer(text);
+
+    let first = match lexer.next()? {
+        Ok(IdTok::Number(n)) => n,
+        _ => return None,
+    };
+
+    let id = match lexer.next() {
+        None => TokenID::Single(first),
+        Some(Ok(IdTok::Dash)) => TokenID::Range(first, expect_number(&mut lexer)?),
+        Some(Ok(IdTok::Dot)) => TokenID::Empty(first, expect_number(&mut lexer)?),
+        _ => return None,
+    };
+
+    match lexer.next() {
+        None => Some(id),
+        _ => None,
+    }
+}
+
+fn expect_number(lexer: &mut logos::Lexer<'_, IdTok>) -> Option<usize> {
+    match lexer.next()? {
+        Ok(IdTok::Number(n)) => Some(n),
+        _ => None,
+    }
+}
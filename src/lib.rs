@@ -57,12 +57,19 @@
 
 use std::{collections::HashMap, error::Error, fmt, str::FromStr};
 
+mod lexer;
 pub mod parsers;
 pub mod token;
+pub mod validate;
 
 pub use token::{Dep, Token, TokenID};
 
-pub use parsers::{parse_file, parse_sentence, parse_token};
+pub use parsers::{
+    parse_file, parse_file_lenient, parse_sentence, parse_sentence_lenient, parse_token,
+    ConlluError,
+};
+
+pub use validate::validate;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseUposError;
@@ -131,6 +138,7 @@ pub struct Sentence {
     meta: Vec<String>,
     tokens: Vec<Token>,
     id_to_index: HashMap<TokenID, usize>,
+    span: Option<std::ops::Range<usize>>,
 }
 
 impl Sentence {
@@ -138,6 +146,21 @@ impl Sentence {
         SentenceBuilder::default()
     }
 
+    /// Byte span covering this sentence's lines in the original source, if
+    /// it was produced by the parser (rather than built by hand).
+    pub fn span(&self) -> Option<std::ops::Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Find the token whose line span contains the given byte offset into
+    /// the original source, if span information was captured during
+    /// parsing.
+    pub fn token_at_offset(&self, offset: usize) -> Option<&Token> {
+        self.tokens
+            .iter()
+            .find(|token| matches!(&token.span, Some(span) if span.contains(&offset)))
+    }
+
     pub fn get_token(&self, id: TokenID) -> Option<&Token> {
         if let Some(idx) = self.id_to_index.get(&id) {
             let token = self.tokens.get(*idx);
@@ -188,6 +211,7 @@ impl IntoIterator for Sentence {
 pub struct SentenceBuilder {
     tokens: Vec<Token>,
     meta: Vec<String>,
+    span: Option<std::ops::Range<usize>>,
 }
 
 impl SentenceBuilder {
@@ -201,6 +225,11 @@ impl SentenceBuilder {
         self
     }
 
+    pub fn with_span(mut self, span: std::ops::Range<usize>) -> SentenceBuilder {
+        self.span = Some(span);
+        self
+    }
+
     pub fn push_token(mut self, token: Token) -> SentenceBuilder {
         self.tokens.push(token);
         self
@@ -219,6 +248,7 @@ impl SentenceBuilder {
             meta: self.meta,
             tokens: self.tokens,
             id_to_index,
+            span: self.span,
         }
     }
 }
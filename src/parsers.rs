@@ -0,0 +1,409 @@
+//! Parsing CoNLL-U formatted text into [Sentence](crate::Sentence)s.
+//!
+//! Parse errors are reported as [ConlluError], which carries a byte span
+//! into the original source so that callers can point users at exactly
+//! the column that broke.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+use std::ops::Range;
+
+use crate::lexer;
+use crate::token::{Dep, FieldSpans, Token, TokenID};
+use crate::{Sentence, UPOS};
+
+/// Names of the ten tab-separated CoNLL-U columns, in order. Field indices
+/// reported on [ConlluError] are 1-based positions into this list.
+const FIELD_NAMES: [&str; 10] = [
+    "ID", "FORM", "LEMMA", "UPOS", "XPOS", "FEATS", "HEAD", "DEPREL", "DEPS", "MISC",
+];
+
+/// A structured, span-aware parse error.
+///
+/// Every error carries the byte offset range (`span`) into the original
+/// source, the 1-based `line` and `column` it starts at, and, when the
+/// problem is specific to one of the ten CoNLL-U columns, the 1-based
+/// `field` index (see [FIELD_NAMES]). The one exception is an error from
+/// [crate::validate] about a sentence or token that carries no span (one
+/// built by hand rather than parsed): it has the span `0..0` and `line`
+/// and `column` 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConlluError {
+    pub span: Range<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub field: Option<usize>,
+    pub message: String,
+}
+
+impl ConlluError {
+    /// The name of the offending column (`"HEAD"`, `"UPOS"`, ...), if this
+    /// error is attributable to a single field.
+    pub fn field_name(&self) -> Option<&'static str> {
+        self.field.and_then(|idx| FIELD_NAMES.get(idx - 1).copied())
+    }
+
+    /// Render this error as a labeled source snippet: the offending source
+    /// line followed by a caret underneath the span, in the style of a
+    /// `codespan-reporting` `Diagnostic` with a single `Label` attached to
+    /// this error's span.
+    pub fn render(&self, source: &str) -> String {
+        let line_text = source.lines().nth(self.line.saturating_sub(1)).unwrap_or("");
+
+        // `column`/`span` are byte offsets, but `str::repeat` below counts
+        // characters; CoNLL-U FORM/LEMMA text is routinely non-ASCII, so the
+        // byte offsets are converted to char counts before they drive the
+        // caret.
+        let byte_start = self.column.saturating_sub(1).min(line_text.len());
+        let byte_len = (self.span.end - self.span.start).max(1);
+        let byte_end = (byte_start + byte_len).min(line_text.len());
+
+        let caret_start = line_text[..byte_start].chars().count();
+        let caret_len = line_text[byte_start..byte_end].chars().count().max(1);
+
+        // The gutter is as wide as the line number, so the caret line lines
+        // up with the source line above it.
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        let mut out = format!("error: {}\n", self.message);
+        out.push_str(&format!(" --> line {}:{}\n", self.line, self.column));
+        out.push_str(&format!("{gutter} |\n{} | {}\n{gutter} | ", self.line, line_text));
+        out.push_str(&" ".repeat(caret_start));
+        out.push_str(&"^".repeat(caret_len));
+        out.push('\n');
+        out
+    }
+}
+
+impl fmt::Display for ConlluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ConlluError {}
+
+fn none_if_underscore(value: &str) -> Option<String> {
+    if value == "_" {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn parse_key_value_list(text: &str) -> HashMap<String, String> {
+    lexer::lex_key_value_list(text)
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// Parse a single tab-separated CoNLL-U token line.
+pub fn parse_token(line: &str) -> Result<Token, ConlluError> {
+    parse_token_at(line, 1, 0)
+}
+
+fn parse_token_at(line: &str, line_no: usize, line_offset: usize) -> Result<Token, ConlluError> {
+    let fields = lexer::lex_fields(line);
+
+    if fields.len() != 10 {
+        return Err(ConlluError {
+            span: line_offset..line_offset + line.len(),
+            line: line_no,
+            column: 1,
+            field: None,
+            message: format!(
+                "expected 10 tab-separated columns, found {}",
+                fields.len()
+            ),
+        });
+    }
+
+    let field_err = |field_idx: usize, (text, col): (&str, usize), message: String| ConlluError {
+        span: (line_offset + col)..(line_offset + col + text.len()),
+        line: line_no,
+        column: col + 1,
+        field: Some(field_idx + 1),
+        message,
+    };
+
+    let id_field = fields[0];
+    let id: TokenID = id_field
+        .0
+        .parse()
+        .map_err(|_| field_err(0, id_field, format!("malformed TokenID '{}'", id_field.0)))?;
+
+    let form = fields[1].0.to_string();
+    let lemma = none_if_underscore(fields[2].0);
+
+    let upos_field = fields[3];
+    let upos = match none_if_underscore(upos_field.0) {
+        Some(text) => Some(text.parse::<UPOS>().map_err(|_| {
+            field_err(3, upos_field, format!("unknown UPOS tag '{text}'"))
+        })?),
+        None => None,
+    };
+
+    let xpos = none_if_underscore(fields[4].0);
+    let features = none_if_underscore(fields[5].0).map(|text| parse_key_value_list(&text));
+
+    let head_field = fields[6];
+    let head = none_if_underscore(head_field.0)
+        .map(|text| {
+            text.parse::<TokenID>()
+                .map_err(|_| field_err(6, head_field, format!("malformed TokenID '{text}'")))
+        })
+        .transpose()?;
+
+    let deprel = none_if_underscore(fields[7].0);
+
+    let deps_field = fields[8];
+    let deps = none_if_underscore(deps_field.0)
+        .map(|text| parse_deps(&text, deps_field, line_no, line_offset))
+        .transpose()?;
+
+    let misc = none_if_underscore(fields[9].0).map(|text| parse_key_value_list(&text));
+
+    let field_range = |(text, col): (&str, usize)| {
+        (line_offset + col)..(line_offset + col + text.len())
+    };
+    let field_spans = FieldSpans {
+        id: field_range(fields[0]),
+        form: field_range(fields[1]),
+        lemma: field_range(fields[2]),
+        upos: field_range(fields[3]),
+        xpos: field_range(fields[4]),
+        feats: field_range(fields[5]),
+        head: field_range(fields[6]),
+        deprel: field_range(fields[7]),
+        deps: field_range(fields[8]),
+        misc: field_range(fields[9]),
+    };
+
+    Ok(Token {
+        id,
+        form,
+        lemma,
+        upos,
+        xpos,
+        features,
+        head,
+        deprel,
+        deps,
+        misc,
+        span: Some(line_offset..line_offset + line.len()),
+        field_spans: Some(field_spans),
+        line: Some(line_no),
+    })
+}
+
+fn parse_deps(
+    text: &str,
+    (_, col): (&str, usize),
+    line_no: usize,
+    line_offset: usize,
+) -> Result<Vec<Dep>, ConlluError> {
+    let deps_err = |message: String| ConlluError {
+        span: (line_offset + col)..(line_offset + col + text.len()),
+        line: line_no,
+        column: col + 1,
+        field: Some(9),
+        message,
+    };
+
+    text.split('|')
+        .map(|entry| {
+            let (head, rel) = entry
+                .split_once(':')
+                .ok_or_else(|| deps_err(format!("malformed DEPS entry '{entry}'")))?;
+            let head = head
+                .parse::<TokenID>()
+                .map_err(|_| deps_err(format!("malformed TokenID '{head}'")))?;
+            Ok(Dep {
+                head,
+                rel: rel.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parse a blank-line-terminated block of CoNLL-U text (comment lines plus
+/// token lines) into a single [Sentence].
+pub fn parse_sentence(source: &str) -> Result<Sentence, ConlluError> {
+    parse_sentence_at(source, 1, 0)
+}
+
+fn parse_sentence_at(source: &str, base_line: usize, base_offset: usize) -> Result<Sentence, ConlluError> {
+    let mut meta = Vec::new();
+    let mut builder = Sentence::builder().with_span(base_offset..base_offset + source.len());
+
+    for (line_no, line_offset, line) in lexer::lex_lines(source) {
+        match line {
+            lexer::Line::Comment(comment) => {
+                meta.push(comment.strip_prefix('#').unwrap_or(comment).trim().to_string());
+            }
+            lexer::Line::Token(text) => {
+                let token = parse_token_at(text, base_line + line_no - 1, base_offset + line_offset)?;
+                builder = builder.push_token(token);
+            }
+        }
+    }
+
+    Ok(builder.with_meta(meta).build())
+}
+
+/// Parse a single sentence, tolerating malformed token lines.
+///
+/// Unlike [parse_sentence], a bad line does not abort parsing: it is
+/// recorded as a diagnostic and skipped, and the remaining lines are still
+/// parsed into the returned [Sentence].
+pub fn parse_sentence_lenient(source: &str) -> (Sentence, Vec<ConlluError>) {
+    parse_sentence_lenient_at(source, 1, 0)
+}
+
+fn parse_sentence_lenient_at(
+    source: &str,
+    base_line: usize,
+    base_offset: usize,
+) -> (Sentence, Vec<ConlluError>) {
+    let mut meta = Vec::new();
+    let mut builder = Sentence::builder().with_span(base_offset..base_offset + source.len());
+    let mut errors = Vec::new();
+
+    for (line_no, line_offset, line) in lexer::lex_lines(source) {
+        match line {
+            lexer::Line::Comment(comment) => {
+                meta.push(comment.strip_prefix('#').unwrap_or(comment).trim().to_string());
+            }
+            lexer::Line::Token(text) => {
+                match parse_token_at(text, base_line + line_no - 1, base_offset + line_offset) {
+                    Ok(token) => builder = builder.push_token(token),
+                    Err(e) => errors.push(e),
+                }
+            }
+        }
+    }
+
+    (builder.with_meta(meta).build(), errors)
+}
+
+/// A lazily-parsed CoNLL-U document: each call to [Iterator::next] reads and
+/// parses one blank-line-delimited sentence from the underlying reader.
+pub struct Doc<R> {
+    reader: BufReader<R>,
+    offset: usize,
+    line_no: usize,
+}
+
+impl<R: Read> Doc<R> {
+    pub fn from_file(reader: R) -> Self {
+        Doc {
+            reader: BufReader::new(reader),
+            offset: 0,
+            line_no: 1,
+        }
+    }
+
+    /// Read the next blank-line-delimited block of raw source, along with
+    /// the 1-based line number and absolute byte offset it starts at.
+    /// Shared by [Doc]'s strict [Iterator] impl and [parse_file_lenient], so
+    /// both agree on exactly how a file is carved into sentence-sized
+    /// blocks.
+    fn next_raw_block(&mut self) -> Option<(usize, usize, String)> {
+        let mut block_start_line = self.line_no;
+        let mut block_start_offset = self.offset;
+        let mut block = String::new();
+        let mut has_content = false;
+
+        loop {
+            let mut raw_line = String::new();
+            let bytes_read = self.reader.read_line(&mut raw_line).ok()?;
+            if bytes_read == 0 {
+                break;
+            }
+            self.offset += bytes_read;
+            self.line_no += 1;
+
+            let trimmed = raw_line.trim_end_matches(['\n', '\r']);
+            if trimmed.is_empty() {
+                if has_content {
+                    break;
+                }
+                block_start_line = self.line_no;
+                block_start_offset = self.offset;
+                continue;
+            }
+            has_content = true;
+            // Keep the line verbatim (including any `\r\n`) so that byte
+            // offsets inside the block match the original source.
+            block.push_str(&raw_line);
+        }
+
+        if !has_content {
+            return None;
+        }
+
+        Some((block_start_line, block_start_offset, block))
+    }
+}
+
+impl<R: Read> Iterator for Doc<R> {
+    type Item = Result<Sentence, ConlluError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (block_start_line, block_start_offset, block) = self.next_raw_block()?;
+        Some(parse_sentence_at(&block, block_start_line, block_start_offset))
+    }
+}
+
+/// A fully-parsed CoNLL-U document: an ordered collection of [Sentence]s.
+pub struct ParsedDoc {
+    sentences: Vec<Sentence>,
+}
+
+impl ParsedDoc {
+    pub fn iter(&self) -> impl Iterator<Item = &Sentence> {
+        self.sentences.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Sentence> {
+        self.sentences.iter_mut()
+    }
+}
+
+impl IntoIterator for ParsedDoc {
+    type Item = Sentence;
+    type IntoIter = std::vec::IntoIter<Sentence>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.sentences.into_iter()
+    }
+}
+
+/// Parse an entire CoNLL-U file, stopping at the first malformed sentence.
+pub fn parse_file<R: Read>(reader: R) -> Result<ParsedDoc, ConlluError> {
+    let sentences = Doc::from_file(reader).collect::<Result<Vec<_>, _>>()?;
+    Ok(ParsedDoc { sentences })
+}
+
+/// Parse an entire CoNLL-U file, tolerating malformed token lines.
+///
+/// Unlike [parse_file], a malformed line does not discard the rest of its
+/// sentence: each block is parsed with [parse_sentence_lenient_at], so a bad
+/// line is recorded as a diagnostic and skipped while every other token in
+/// that sentence (and every other sentence in the file) is still returned.
+pub fn parse_file_lenient<R: Read>(reader: R) -> (ParsedDoc, Vec<ConlluError>) {
+    let mut doc = Doc::from_file(reader);
+    let mut sentences = Vec::new();
+    let mut errors = Vec::new();
+
+    while let Some((block_start_line, block_start_offset, block)) = doc.next_raw_block() {
+        let (sentence, block_errors) =
+            parse_sentence_lenient_at(&block, block_start_line, block_start_offset);
+        sentences.push(sentence);
+        errors.extend(block_errors);
+    }
+
+    (ParsedDoc { sentences }, errors)
+}
@@ -0,0 +1,104 @@
+//! Types describing a single CoNLL-U token line and its fields.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::lexer;
+use crate::UPOS;
+
+/// The identifier of a token within a sentence.
+///
+/// CoNLL-U distinguishes three kinds of ids: a plain word id (`3`), a
+/// multiword token range (`3-4`), and an empty node introduced for
+/// enhanced dependencies (`3.1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TokenID {
+    Single(usize),
+    Range(usize, usize),
+    Empty(usize, usize),
+}
+
+impl fmt::Display for TokenID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenID::Single(id) => write!(f, "{id}"),
+            TokenID::Range(start, end) => write!(f, "{start}-{end}"),
+            TokenID::Empty(id, sub) => write!(f, "{id}.{sub}"),
+        }
+    }
+}
+
+/// Error produced when a `TokenID` column cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTokenIdError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseTokenIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed TokenID '{}'", self.input)
+    }
+}
+
+impl std::error::Error for ParseTokenIdError {}
+
+impl FromStr for TokenID {
+    type Err = ParseTokenIdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        lexer::lex_token_id(value).ok_or_else(|| ParseTokenIdError {
+            input: value.to_string(),
+        })
+    }
+}
+
+/// An entry in the enhanced dependency graph (the `DEPS` column).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dep {
+    pub head: TokenID,
+    pub rel: String,
+}
+
+/// The byte span of each of the ten CoNLL-U columns of a [Token], as
+/// captured by the parser. Populated together: either every field of a
+/// `FieldSpans` is known, or the whole thing is absent (see
+/// [Token::field_spans]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSpans {
+    pub id: Range<usize>,
+    pub form: Range<usize>,
+    pub lemma: Range<usize>,
+    pub upos: Range<usize>,
+    pub xpos: Range<usize>,
+    pub feats: Range<usize>,
+    pub head: Range<usize>,
+    pub deprel: Range<usize>,
+    pub deps: Range<usize>,
+    pub misc: Range<usize>,
+}
+
+/// A single CoNLL-U token, corresponding to one tab-separated line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub id: TokenID,
+    pub form: String,
+    pub lemma: Option<String>,
+    pub upos: Option<UPOS>,
+    pub xpos: Option<String>,
+    pub features: Option<HashMap<String, String>>,
+    pub head: Option<TokenID>,
+    pub deprel: Option<String>,
+    pub deps: Option<Vec<Dep>>,
+    pub misc: Option<HashMap<String, String>>,
+    /// Byte span of this token's whole line in the original source, if it
+    /// was produced by the parser (rather than built by hand).
+    pub span: Option<Range<usize>>,
+    /// Byte spans of each individual column, if this token was produced by
+    /// the parser.
+    pub field_spans: Option<FieldSpans>,
+    /// 1-based line number of this token in the original source, if it was
+    /// produced by the parser.
+    pub line: Option<usize>,
+}
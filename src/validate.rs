@@ -0,0 +1,263 @@
+//! Structural validation of parsed dependency trees.
+//!
+//! [crate::parsers] only checks that individual fields are well-formed; it
+//! never verifies that a [Sentence] is a coherent dependency structure.
+//! [validate] enforces the CoNLL-U well-formedness invariants on top of
+//! that: every `HEAD` resolves to a real token (or root), the `HEAD`
+//! column contains no cycles, token ids are contiguous and non-overlapping,
+//! and every enhanced `DEPS` entry resolves to a real token.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::token::{Token, TokenID};
+use crate::{ConlluError, Sentence};
+
+const ROOT: TokenID = TokenID::Single(0);
+
+/// Check that `sentence` is a well-formed CoNLL-U dependency structure,
+/// returning every violation found rather than stopping at the first (so
+/// it composes with [crate::parsers::parse_sentence_lenient]).
+pub fn validate(sentence: &Sentence) -> Vec<ConlluError> {
+    let mut errors = Vec::new();
+
+    errors.extend(check_heads(sentence));
+    errors.extend(check_cycles(sentence));
+    errors.extend(check_ids(sentence));
+    errors.extend(check_deps(sentence));
+
+    errors
+}
+
+/// Build a [ConlluError] anchored at `token`: `line` comes from the
+/// token's own line number and `column` is derived from `span` (or the
+/// token's whole-line span, if `span` is `None`) against the start of that
+/// line, the same way parser errors are built in [crate::parsers].
+fn token_error(token: &Token, field: usize, span: Option<Range<usize>>, message: String) -> ConlluError {
+    let span = span.or_else(|| token.span.clone()).unwrap_or(0..0);
+    let column = token
+        .span
+        .as_ref()
+        .map(|line_span| span.start.saturating_sub(line_span.start) + 1)
+        .unwrap_or(0);
+
+    ConlluError {
+        span,
+        line: token.line.unwrap_or(0),
+        column,
+        field: Some(field),
+        message,
+    }
+}
+
+/// Build a [ConlluError] for a violation that isn't attributable to one
+/// specific token, anchoring it at the sentence's first token when one
+/// exists.
+fn sentence_error(sentence: &Sentence, field: usize, message: String) -> ConlluError {
+    match sentence.token_iter().next() {
+        Some(token) => token_error(token, field, None, message),
+        None => ConlluError {
+            span: sentence.span().unwrap_or(0..0),
+            line: 0,
+            column: 0,
+            field: Some(field),
+            message,
+        },
+    }
+}
+
+fn check_heads(sentence: &Sentence) -> Vec<ConlluError> {
+    let mut errors = Vec::new();
+    let mut root_count = 0;
+
+    for token in sentence.token_iter() {
+        match token.head {
+            None => {}
+            Some(head) if head == ROOT => root_count += 1,
+            Some(head @ TokenID::Single(_)) if sentence.get_token(head).is_some() => {}
+            Some(head) => {
+                let message = match head {
+                    TokenID::Single(_) => format!(
+                        "HEAD {head} of token {} does not reference a token in this sentence",
+                        token.id
+                    ),
+                    _ => format!("HEAD must be a single token id or 0, found '{head}'"),
+                };
+                errors.push(token_error(
+                    token,
+                    7,
+                    token.field_spans.as_ref().map(|s| s.head.clone()),
+                    message,
+                ));
+            }
+        }
+    }
+
+    if root_count != 1 {
+        errors.push(sentence_error(
+            sentence,
+            7,
+            format!("expected exactly one token attached to root, found {root_count}"),
+        ));
+    }
+
+    errors
+}
+
+/// Detect cycles in the `HEAD` column by treating it as a functional graph
+/// (each single-word token has at most one outgoing edge, to its head) and
+/// walking each unresolved chain iteratively until it reaches root, joins an
+/// already-resolved chain, or revisits a token from the current walk.
+fn check_cycles(sentence: &Sentence) -> Vec<ConlluError> {
+    let head_of: HashMap<TokenID, TokenID> = sentence
+        .token_iter()
+        .filter_map(|t| match (t.id, t.head) {
+            (id @ TokenID::Single(_), Some(head)) if head != ROOT => Some((id, head)),
+            _ => None,
+        })
+        .collect();
+
+    let mut resolved: HashMap<TokenID, bool> = HashMap::new();
+    let mut errors = Vec::new();
+
+    for &start in head_of.keys() {
+        if resolved.contains_key(&start) {
+            continue;
+        }
+
+        let mut chain = Vec::new();
+        let mut seen_at: HashMap<TokenID, usize> = HashMap::new();
+        let mut current = start;
+
+        loop {
+            if resolved.contains_key(&current) {
+                break;
+            }
+            if let Some(&pos) = seen_at.get(&current) {
+                let cycle = &chain[pos..];
+                let message = format!(
+                    "cycle detected in HEAD graph: {}",
+                    cycle
+                        .iter()
+                        .map(TokenID::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                );
+                errors.push(match sentence.get_token(cycle[0]) {
+                    Some(token) => token_error(token, 7, None, message),
+                    None => sentence_error(sentence, 7, message),
+                });
+                break;
+            }
+            seen_at.insert(current, chain.len());
+            chain.push(current);
+
+            match head_of.get(&current) {
+                Some(&next) => current = next,
+                None => break,
+            }
+        }
+
+        for id in chain {
+            resolved.insert(id, true);
+        }
+    }
+
+    errors
+}
+
+fn check_ids(sentence: &Sentence) -> Vec<ConlluError> {
+    let mut errors = Vec::new();
+
+    let mut singles: Vec<usize> = sentence
+        .token_iter()
+        .filter_map(|t| match t.id {
+            TokenID::Single(n) => Some(n),
+            _ => None,
+        })
+        .collect();
+    singles.sort_unstable();
+
+    if let Some((_, &n)) = singles.iter().enumerate().find(|&(i, &n)| n != i + 1) {
+        let message = format!(
+            "token ids must form a contiguous 1..={} sequence, found {singles:?}",
+            singles.len()
+        );
+        errors.push(match sentence.get_token(TokenID::Single(n)) {
+            Some(token) => token_error(token, 1, None, message),
+            None => sentence_error(sentence, 1, message),
+        });
+    }
+
+    let mut ranges: Vec<(usize, usize)> = sentence
+        .token_iter()
+        .filter_map(|t| match t.id {
+            TokenID::Range(start, end) => Some((start, end)),
+            _ => None,
+        })
+        .collect();
+    ranges.sort_unstable();
+
+    for window in ranges.windows(2) {
+        let (_, prev_end) = window[0];
+        let (next_start, _) = window[1];
+        if next_start <= prev_end {
+            let message = format!(
+                "overlapping multiword token ids {}-{} and {}-{}",
+                window[0].0, window[0].1, window[1].0, window[1].1
+            );
+            errors.push(match sentence.get_token(TokenID::Range(window[1].0, window[1].1)) {
+                Some(token) => token_error(token, 1, None, message),
+                None => sentence_error(sentence, 1, message),
+            });
+        }
+    }
+
+    let mut empties: Vec<(usize, usize)> = sentence
+        .token_iter()
+        .filter_map(|t| match t.id {
+            TokenID::Empty(id, sub) => Some((id, sub)),
+            _ => None,
+        })
+        .collect();
+    empties.sort_unstable();
+
+    for window in empties.windows(2) {
+        if window[0] == window[1] {
+            let (id, sub) = window[0];
+            let message = format!("duplicate empty-node id {id}.{sub}");
+            errors.push(match sentence.get_token(TokenID::Empty(id, sub)) {
+                Some(token) => token_error(token, 1, None, message),
+                None => sentence_error(sentence, 1, message),
+            });
+        }
+    }
+
+    errors
+}
+
+fn check_deps(sentence: &Sentence) -> Vec<ConlluError> {
+    let mut errors = Vec::new();
+
+    for token in sentence.token_iter() {
+        let Some(deps) = &token.deps else {
+            continue;
+        };
+
+        for dep in deps {
+            if dep.head != ROOT && sentence.get_token(dep.head).is_none() {
+                errors.push(token_error(
+                    token,
+                    9,
+                    token.field_spans.as_ref().map(|s| s.deps.clone()),
+                    format!(
+                        "DEPS head {} of token {} does not reference a token in this sentence",
+                        dep.head, token.id
+                    ),
+                ));
+            }
+        }
+    }
+
+    errors
+}
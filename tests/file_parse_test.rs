@@ -16,6 +16,13 @@ fn test_file_parse() {
 
     let token = token_iter.next().unwrap();
 
+    assert!(token.span.is_some());
+    assert!(token.field_spans.is_some());
+    assert_eq!(token.line, Some(3));
+
+    let span = token.span.clone();
+    let field_spans = token.field_spans.clone();
+
     assert_eq!(
         token,
         Token {
@@ -40,7 +47,10 @@ fn test_file_parse() {
                     rel: "nsubj".to_string()
                 }
             ]),
-            misc: None
+            misc: None,
+            span,
+            field_spans,
+            line: token.line
         }
     )
 }
@@ -0,0 +1,21 @@
+use rs_conllu::{parse_file_lenient, parse_sentence_lenient};
+
+const SOURCE: &str = "1\tThey\tthey\tPRON\tPRP\t_\t2\tnsubj\t_\t_\nbad line\n2\tbuy\tbuy\tVERB\tVBP\t_\t0\troot\t_\t_\n";
+
+#[test]
+fn parse_sentence_lenient_keeps_good_tokens() {
+    let (sentence, errors) = parse_sentence_lenient(SOURCE);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(sentence.token_iter().count(), 2);
+}
+
+#[test]
+fn parse_file_lenient_matches_sentence_granularity() {
+    let (doc, errors) = parse_file_lenient(SOURCE.as_bytes());
+
+    assert_eq!(errors.len(), 1);
+
+    let sentence = doc.into_iter().next().unwrap();
+    assert_eq!(sentence.token_iter().count(), 2);
+}
@@ -0,0 +1,15 @@
+use rs_conllu::parse_token;
+
+#[test]
+fn render_places_caret_by_char_count_not_byte_count() {
+    let source = "1\trêves\trêve\tNOUN\t_\t_\tbad\t_\t_\t_";
+    let err = parse_token(source).unwrap_err();
+
+    let rendered = err.render(source);
+    let caret_line = rendered.lines().last().unwrap();
+
+    // "rêves" and "rêve" each have one 2-byte char, so the HEAD field
+    // ("bad") starts two columns earlier than its byte offset would suggest.
+    let expected_caret_column = source.chars().take_while(|&c| c != 'b').count();
+    assert_eq!(caret_line.len(), "  | ".len() + expected_caret_column + 3);
+}
@@ -0,0 +1,25 @@
+use std::str::FromStr;
+
+use rs_conllu::{parse_token, TokenID};
+
+#[test]
+fn token_id_parses_single_range_and_empty_shapes() {
+    assert_eq!(TokenID::from_str("3").unwrap(), TokenID::Single(3));
+    assert_eq!(TokenID::from_str("3-4").unwrap(), TokenID::Range(3, 4));
+    assert_eq!(TokenID::from_str("3.1").unwrap(), TokenID::Empty(3, 1));
+}
+
+#[test]
+fn token_id_rejects_malformed_input() {
+    assert!(TokenID::from_str("3-").is_err());
+    assert!(TokenID::from_str("3.1.2").is_err());
+    assert!(TokenID::from_str("abc").is_err());
+}
+
+#[test]
+fn misc_column_keeps_value_with_embedded_equals_signs() {
+    let token = parse_token("1\tThey\tthey\t_\t_\t_\t_\t_\t_\tKey=a=b=c").unwrap();
+
+    let misc = token.misc.unwrap();
+    assert_eq!(misc.get("Key").unwrap(), "a=b=c");
+}
@@ -0,0 +1,39 @@
+use rs_conllu::{parse_sentence, validate};
+
+#[test]
+fn validate_accepts_well_formed_sentence() {
+    let sentence = parse_sentence(
+        "1\tThey\tthey\tPRON\tPRP\t_\t2\tnsubj\t_\t_\n2\tbuy\tbuy\tVERB\tVBP\t_\t0\troot\t_\t_\n",
+    )
+    .unwrap();
+
+    assert_eq!(validate(&sentence), vec![]);
+}
+
+#[test]
+fn validate_reports_head_pointing_past_the_sentence() {
+    let sentence =
+        parse_sentence("1\tThey\tthey\tPRON\tPRP\t_\t9\tnsubj\t_\t_\n").unwrap();
+
+    let errors = validate(&sentence);
+
+    assert_eq!(errors.len(), 2);
+    let head_error = errors
+        .iter()
+        .find(|e| e.field_name() == Some("HEAD") && e.message.contains("does not reference"))
+        .expect("missing HEAD error");
+    assert_eq!(head_error.line, 1);
+    assert_eq!(head_error.column, 24);
+}
+
+#[test]
+fn validate_detects_head_cycles() {
+    let sentence = parse_sentence(
+        "1\tThey\tthey\tPRON\tPRP\t_\t2\tnsubj\t_\t_\n2\tbuy\tbuy\tVERB\tVBP\t_\t1\tconj\t_\t_\n",
+    )
+    .unwrap();
+
+    let errors = validate(&sentence);
+
+    assert!(errors.iter().any(|e| e.message.contains("cycle detected")));
+}